@@ -14,7 +14,9 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with game_of_life.  If not, see <http://www.gnu.org/licenses/>.
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::error::Error;
 use std::fmt::Display;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -34,87 +36,457 @@ impl Display for Cell {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// Determines how neighbour coordinates that fall off the edge of the board
+/// are treated when counting live neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topology {
+    /// Out-of-range neighbours are simply absent, so edges and corners have
+    /// fewer than 8 neighbours.
+    Bounded,
+    /// Out-of-range neighbours wrap around to the opposite edge, so the
+    /// board behaves like the surface of a torus.
+    Torus,
+}
+
+/// A birth/survival rule in the standard `B<digits>/S<digits>` notation,
+/// e.g. `B3/S23` for conventional Life or `B36/S23` for HighLife.
+///
+/// Each digit is a live-neighbour count (0..=8); `born[n]` is `true` when a
+/// dead cell with `n` live neighbours comes alive, and `survive[n]` is
+/// `true` when a live cell with `n` live neighbours stays alive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    born: [bool; 9],
+    survive: [bool; 9],
+}
+
+impl Rule {
+    pub fn parse(rule: &str) -> Result<Self, RuleParseError> {
+        let (born_part, survive_part) = rule
+            .split_once('/')
+            .ok_or_else(|| RuleParseError::Malformed(rule.to_string()))?;
+
+        Ok(Self {
+            born: Self::parse_counts(born_part, 'B')?,
+            survive: Self::parse_counts(survive_part, 'S')?,
+        })
+    }
+
+    fn parse_counts(part: &str, prefix: char) -> Result<[bool; 9], RuleParseError> {
+        let digits = part
+            .strip_prefix(prefix)
+            .ok_or_else(|| RuleParseError::Malformed(part.to_string()))?;
+
+        let mut counts = [false; 9];
+        for ch in digits.chars() {
+            let n = ch
+                .to_digit(10)
+                .ok_or_else(|| RuleParseError::Malformed(part.to_string()))?
+                as usize;
+
+            if n > 8 {
+                return Err(RuleParseError::CountOutOfRange(n));
+            }
+
+            counts[n] = true;
+        }
+
+        Ok(counts)
+    }
+}
+
+impl Default for Rule {
+    /// Conventional Life: a dead cell with 3 neighbours is born, a live
+    /// cell with 2 or 3 neighbours survives.
+    fn default() -> Self {
+        Self::parse("B3/S23").expect("B3/S23 is a valid rule string")
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "B")?;
+        for n in 0..=8 {
+            if self.born[n] {
+                write!(f, "{n}")?;
+            }
+        }
+
+        write!(f, "/S")?;
+        for n in 0..=8 {
+            if self.survive[n] {
+                write!(f, "{n}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// An error parsing a [`Rule`] from its `B<digits>/S<digits>` notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RuleParseError {
+    /// The string wasn't of the form `B<digits>/S<digits>`.
+    Malformed(String),
+    /// A neighbour count fell outside the valid `0..=8` range.
+    CountOutOfRange(usize),
+}
+
+impl Display for RuleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleParseError::Malformed(part) => {
+                write!(
+                    f,
+                    "malformed rule string, expected B<digits>/S<digits>: {part}"
+                )
+            }
+            RuleParseError::CountOutOfRange(n) => {
+                write!(f, "neighbour count {n} is outside the valid 0..=8 range")
+            }
+        }
+    }
+}
+
+impl Error for RuleParseError {}
+
+/// An error parsing a [`Board`] from the Run Length Encoded Life format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RleParseError {
+    /// The input had no header line at all.
+    MissingHeader,
+    /// The header line wasn't of the form `x = <w>, y = <h>[, rule = <rule>]`.
+    MalformedHeader(String),
+    /// The header's `rule` field wasn't a valid [`Rule`] string.
+    InvalidRule(RuleParseError),
+    /// The body contained a character that isn't part of the RLE token set.
+    UnexpectedToken(char),
+    /// A run-count prefix overflowed `usize`.
+    RunCountOverflow,
+}
+
+impl Display for RleParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RleParseError::MissingHeader => write!(f, "RLE input has no header line"),
+            RleParseError::MalformedHeader(header) => {
+                write!(f, "malformed RLE header: {header}")
+            }
+            RleParseError::InvalidRule(err) => write!(f, "invalid rule in RLE header: {err}"),
+            RleParseError::UnexpectedToken(ch) => {
+                write!(f, "unexpected character in RLE body: {ch}")
+            }
+            RleParseError::RunCountOverflow => write!(f, "RLE run count overflowed usize"),
+        }
+    }
+}
+
+impl Error for RleParseError {}
+
+#[derive(Debug)]
 pub struct Board {
     size: (usize, usize),
-    state: Vec<Vec<Cell>>,
+    state: Vec<Cell>,
+    /// Scratch buffer for the next generation, swapped with `state` each
+    /// tick so `next_board_state` never reallocates.
+    scratch: Vec<Cell>,
+    topology: Topology,
+    rule: Rule,
 }
 
+impl PartialEq for Board {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self.state == other.state
+            && self.topology == other.topology
+            && self.rule == other.rule
+    }
+}
+
+impl Eq for Board {}
+
 impl Board {
     pub fn dead_state(width: usize, height: usize) -> Self {
         Self {
             size: (width, height),
-            state: vec![vec![Cell::Dead; width]; height],
+            state: vec![Cell::Dead; width * height],
+            scratch: vec![Cell::Dead; width * height],
+            topology: Topology::Bounded,
+            rule: Rule::default(),
         }
     }
 
     pub fn random_state(width: usize, height: usize) -> Self {
+        let seed = rand::thread_rng().gen();
+
+        Self::random_state_seeded(width, height, seed, 0.85)
+    }
+
+    /// Like [`Board::random_state`], but deterministic: the same `seed` and
+    /// `threshold` always produce the same board. A cell is alive when a
+    /// `StdRng` seeded with `seed` draws an `f32` above `threshold`, so a
+    /// lower threshold means a denser board.
+    pub fn random_state_seeded(width: usize, height: usize, seed: u64, threshold: f32) -> Self {
         let mut board = Self::dead_state(width, height);
-        let mut rng = rand::thread_rng();
+        let mut rng = StdRng::seed_from_u64(seed);
 
-        for row in board.state.iter_mut() {
-            for cell in row.iter_mut() {
-                if rng.gen::<f32>() > 0.85 {
-                    *cell = Cell::Alive;
-                }
+        for cell in board.state.iter_mut() {
+            if rng.gen::<f32>() > threshold {
+                *cell = Cell::Alive;
             }
         }
 
         board
     }
 
-    pub fn next_board_state(&self) -> Self {
+    /// Returns the cell at `(x, y)`.
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.state[self.index(x, y)]
+    }
+
+    /// Sets the cell at `(x, y)`.
+    pub fn set(&mut self, x: usize, y: usize, cell: Cell) {
+        let index = self.index(x, y);
+        self.state[index] = cell;
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        let (width, height) = self.size;
+        assert!(
+            x < width && y < height,
+            "coordinates ({x}, {y}) out of bounds for a {width}x{height} board"
+        );
+
+        y * width + x
+    }
+
+    /// The current generation's cells (dead and alive), in row-major order,
+    /// without the internal scratch
+    /// buffer. Crate-internal callers that need to snapshot a board (e.g.
+    /// [`crate::simulation::Simulation`]'s history) should use this instead
+    /// of cloning the whole `Board`.
+    pub(crate) fn cells(&self) -> &[Cell] {
+        &self.state
+    }
+
+    /// Returns a copy of this board with the given boundary [`Topology`].
+    pub fn with_topology(mut self, topology: Topology) -> Self {
+        self.topology = topology;
+        self
+    }
+
+    /// Returns a copy of this board with the given birth/survival [`Rule`].
+    pub fn with_rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Parses a board from the Run Length Encoded Life format, e.g.:
+    ///
+    /// ```text
+    /// x = 3, y = 3, rule = B3/S23
+    /// bo$2bo$3o!
+    /// ```
+    ///
+    /// The board's [`Rule`] is taken from the header's `rule` field,
+    /// defaulting to `B3/S23` when absent.
+    pub fn from_rle(rle: &str) -> Result<Self, RleParseError> {
+        let mut lines = rle
+            .lines()
+            .filter(|line| !line.trim_start().starts_with('#'));
+        let header = lines.next().ok_or(RleParseError::MissingHeader)?;
+        let (width, height, rule) = Self::parse_rle_header(header)?;
+
+        let mut board = Self::dead_state(width, height).with_rule(rule);
+        let (mut x, mut y) = (0, 0);
+        let mut run: Option<usize> = None;
+
+        for token in lines
+            .flat_map(|line| line.chars())
+            .filter(|c| !c.is_whitespace())
+        {
+            match token {
+                '0'..='9' => {
+                    let digit = token.to_digit(10).unwrap() as usize;
+                    run = Some(
+                        run.unwrap_or(0)
+                            .checked_mul(10)
+                            .and_then(|n| n.checked_add(digit))
+                            .ok_or(RleParseError::RunCountOverflow)?,
+                    );
+                }
+                'b' | 'o' => {
+                    let cell = if token == 'o' {
+                        Cell::Alive
+                    } else {
+                        Cell::Dead
+                    };
+                    for _ in 0..run.take().unwrap_or(1) {
+                        // A declared run can be arbitrarily large (and is
+                        // otherwise untrusted input), so stop as soon as it
+                        // runs off the right edge rather than burning CPU on
+                        // iterations that can never land inside the board.
+                        if x >= width {
+                            break;
+                        }
+                        if y < height {
+                            board.set(x, y, cell);
+                        }
+                        x += 1;
+                    }
+                }
+                '$' => {
+                    y += run.take().unwrap_or(1);
+                    x = 0;
+                }
+                '!' => break,
+                _ => return Err(RleParseError::UnexpectedToken(token)),
+            }
+        }
+
+        Ok(board)
+    }
+
+    fn parse_rle_header(header: &str) -> Result<(usize, usize, Rule), RleParseError> {
+        let malformed = || RleParseError::MalformedHeader(header.to_string());
+
+        let mut width = None;
+        let mut height = None;
+        let mut rule = Rule::default();
+
+        for field in header.split(',') {
+            let (key, value) = field.split_once('=').ok_or_else(malformed)?;
+
+            match key.trim() {
+                "x" => width = Some(value.trim().parse().map_err(|_| malformed())?),
+                "y" => height = Some(value.trim().parse().map_err(|_| malformed())?),
+                "rule" => rule = Rule::parse(value.trim()).map_err(RleParseError::InvalidRule)?,
+                _ => {}
+            }
+        }
+
+        Ok((
+            width.ok_or_else(malformed)?,
+            height.ok_or_else(malformed)?,
+            rule,
+        ))
+    }
+
+    /// Encodes this board into the Run Length Encoded Life format.
+    pub fn to_rle(&self) -> String {
+        let (width, height) = self.size;
+        let mut body = String::new();
+
+        for row in 0..height {
+            let mut col = 0;
+            while col < width {
+                let cell = self.get(col, row);
+                let mut run = 1;
+                while col + run < width && self.get(col + run, row) == cell {
+                    run += 1;
+                }
+
+                if run > 1 {
+                    body.push_str(&run.to_string());
+                }
+                body.push(if cell == Cell::Alive { 'o' } else { 'b' });
+
+                col += run;
+            }
+
+            if row + 1 < height {
+                body.push('$');
+            }
+        }
+        body.push('!');
+
+        format!("x = {width}, y = {height}, rule = {}\n{body}", self.rule)
+    }
+
+    /// Advances this board to the next generation in place, reusing the
+    /// board's scratch buffer instead of allocating a new one.
+    pub fn next_board_state(&mut self) {
         let (width, height) = self.size;
-        let mut next_state = Board::dead_state(width, height);
 
         for row in 0..height {
             for col in 0..width {
-                next_state.state[row][col] = self.next_cell_state((col as isize, row as isize));
+                let index = row * width + col;
+                self.scratch[index] = self.next_cell_state((col as isize, row as isize));
             }
         }
 
-        next_state
+        std::mem::swap(&mut self.state, &mut self.scratch);
     }
 
     fn next_cell_state(&self, coords: (isize, isize)) -> Cell {
         let (x, y) = coords;
         let (width, height) = (self.size.0 as isize, self.size.1 as isize);
-        let mut live_neighbours = 0;
+        let mut live_neighbours: usize = 0;
+        // On a torus narrower or shorter than 3 cells, two of the nine
+        // offsets below can wrap onto the same physical coordinate (e.g. a
+        // width-1 board wraps both `x - 1` and `x + 1` onto `x`). Track
+        // which wrapped coordinates have already been counted so each
+        // physical neighbour contributes at most once.
+        let mut counted: Vec<(isize, isize)> = Vec::with_capacity(8);
 
         for row in y - 1..=y + 1 {
-            if row < 0 || row >= height {
-                continue;
-            }
+            let wrapped_row = match self.topology {
+                Topology::Bounded => {
+                    if row < 0 || row >= height {
+                        continue;
+                    }
+                    row
+                }
+                Topology::Torus => (row + height) % height,
+            };
 
             for col in x - 1..=x + 1 {
-                if (col < 0 || col >= width) || (x == col && y == row) {
+                let wrapped_col = match self.topology {
+                    Topology::Bounded => {
+                        if col < 0 || col >= width {
+                            continue;
+                        }
+                        col
+                    }
+                    Topology::Torus => (col + width) % width,
+                };
+
+                if x == wrapped_col && y == wrapped_row {
                     continue;
                 }
 
-                if self.state[row as usize][col as usize] == Cell::Alive {
+                if counted.contains(&(wrapped_row, wrapped_col)) {
+                    continue;
+                }
+                counted.push((wrapped_row, wrapped_col));
+
+                if self.get(wrapped_col as usize, wrapped_row as usize) == Cell::Alive {
                     live_neighbours += 1;
                 }
             }
         }
 
-        match self.state[y as usize][x as usize] {
-            Cell::Alive => match live_neighbours {
-                2 | 3 => Cell::Alive,
-                _ => Cell::Dead,
-            },
-            Cell::Dead => match live_neighbours {
-                3 => Cell::Alive,
-                _ => Cell::Dead,
-            },
+        let alive = self.get(x as usize, y as usize) == Cell::Alive;
+        let survives_or_is_born = if alive {
+            self.rule.survive[live_neighbours]
+        } else {
+            self.rule.born[live_neighbours]
+        };
+
+        if survives_or_is_born {
+            Cell::Alive
+        } else {
+            Cell::Dead
         }
     }
 }
 
 impl Display for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (width, _) = self.size;
         let print = self
             .state
-            .iter()
+            .chunks(width)
             .map(|row| {
                 row.iter()
                     .map(|state| format!("{}", state))
@@ -132,90 +504,271 @@ impl Display for Board {
 mod tests {
     use super::*;
 
+    /// Builds a board from a grid of rows, e.g. `[[Dead, Alive], [Alive, Dead]]`.
+    fn board_from_rows(rows: &[&[Cell]]) -> Board {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |row| row.len());
+        let mut board = Board::dead_state(width, height);
+
+        for (y, row) in rows.iter().enumerate() {
+            for (x, cell) in row.iter().enumerate() {
+                board.set(x, y, *cell);
+            }
+        }
+
+        board
+    }
+
     #[test]
     fn test_dead_state() {
         let dead_state = Board::dead_state(4, 4);
-        assert!(dead_state
-            .state
-            .iter()
-            .all(|row| row.iter().all(|state| *state == Cell::Dead)))
+        assert!(dead_state.state.iter().all(|state| *state == Cell::Dead))
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_get_panics_on_out_of_bounds_x() {
+        let board = Board::dead_state(3, 3);
+        board.get(5, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn test_set_panics_on_out_of_bounds_y() {
+        let mut board = Board::dead_state(3, 3);
+        board.set(0, 5, Cell::Alive);
     }
 
     #[test]
     fn test_random_state() {
-        let random_state = Board::random_state(3, 3);
-        assert!(random_state
-            .state
-            .iter()
-            .any(|row| row.iter().any(|state| *state == Cell::Alive)))
+        let random_state = Board::random_state(20, 20);
+        assert!(random_state.state.contains(&Cell::Alive))
+    }
+
+    #[test]
+    fn test_random_state_seeded_is_deterministic() {
+        let first = Board::random_state_seeded(8, 8, 42, 0.85);
+        let second = Board::random_state_seeded(8, 8, 42, 0.85);
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_random_state_seeded_threshold_controls_density() {
+        let sparse = Board::random_state_seeded(16, 16, 7, 0.99);
+        let dense = Board::random_state_seeded(16, 16, 7, 0.01);
+
+        let sparse_count = sparse.state.iter().filter(|&&c| c == Cell::Alive).count();
+        let dense_count = dense.state.iter().filter(|&&c| c == Cell::Alive).count();
+
+        assert!(dense_count > sparse_count);
     }
 
     #[test]
     fn test_dead_stay_dead() {
-        let inital_state = Board {
-            state: vec![
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-            ],
-            size: (3, 3),
-        };
-        let expected_state = Board {
-            state: vec![
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-            ],
-            size: (3, 3),
-        };
-        let next_state = inital_state.next_board_state();
+        let mut inital_state = board_from_rows(&[
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+        ]);
+        let expected_state = board_from_rows(&[
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+        ]);
+        inital_state.next_board_state();
 
-        assert!(next_state == expected_state);
+        assert!(inital_state == expected_state);
     }
 
     #[test]
     fn test_should_come_alive() {
-        let inital_state = Board {
-            state: vec![
-                vec![Cell::Dead, Cell::Dead, Cell::Alive],
-                vec![Cell::Dead, Cell::Alive, Cell::Alive],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-            ],
-            size: (3, 3),
-        };
-        let expected_state = Board {
-            state: vec![
-                vec![Cell::Dead, Cell::Alive, Cell::Alive],
-                vec![Cell::Dead, Cell::Alive, Cell::Alive],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-            ],
-            size: (3, 3),
-        };
-        let next_state = inital_state.next_board_state();
+        let mut inital_state = board_from_rows(&[
+            &[Cell::Dead, Cell::Dead, Cell::Alive],
+            &[Cell::Dead, Cell::Alive, Cell::Alive],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+        ]);
+        let expected_state = board_from_rows(&[
+            &[Cell::Dead, Cell::Alive, Cell::Alive],
+            &[Cell::Dead, Cell::Alive, Cell::Alive],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+        ]);
+        inital_state.next_board_state();
 
-        assert!(next_state == expected_state);
+        assert!(inital_state == expected_state);
     }
 
     #[test]
     fn test_should_die_and_come_alive() {
-        let inital_state = Board {
-            state: vec![
-                vec![Cell::Alive, Cell::Alive, Cell::Alive],
-                vec![Cell::Dead, Cell::Alive, Cell::Alive],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-            ],
-            size: (3, 3),
-        };
-        let expected_state = Board {
-            state: vec![
-                vec![Cell::Alive, Cell::Dead, Cell::Alive],
-                vec![Cell::Alive, Cell::Dead, Cell::Alive],
-                vec![Cell::Dead, Cell::Dead, Cell::Dead],
-            ],
-            size: (3, 3),
-        };
-        let next_state = inital_state.next_board_state();
+        let mut inital_state = board_from_rows(&[
+            &[Cell::Alive, Cell::Alive, Cell::Alive],
+            &[Cell::Dead, Cell::Alive, Cell::Alive],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+        ]);
+        let expected_state = board_from_rows(&[
+            &[Cell::Alive, Cell::Dead, Cell::Alive],
+            &[Cell::Alive, Cell::Dead, Cell::Alive],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+        ]);
+        inital_state.next_board_state();
+
+        assert!(inital_state == expected_state);
+    }
+
+    #[test]
+    fn test_bounded_topology_ignores_far_edge() {
+        let mut inital_state = board_from_rows(&[
+            &[Cell::Dead, Cell::Dead, Cell::Alive],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+            &[Cell::Alive, Cell::Dead, Cell::Alive],
+        ])
+        .with_topology(Topology::Bounded);
+        inital_state.next_board_state();
+
+        assert_eq!(inital_state.get(0, 0), Cell::Dead);
+    }
+
+    #[test]
+    fn test_torus_topology_wraps_neighbours() {
+        let mut inital_state = board_from_rows(&[
+            &[Cell::Dead, Cell::Dead, Cell::Alive],
+            &[Cell::Dead, Cell::Dead, Cell::Dead],
+            &[Cell::Alive, Cell::Dead, Cell::Alive],
+        ])
+        .with_topology(Topology::Torus);
+        inital_state.next_board_state();
+
+        // (0, 0) has three live neighbours once the top and left edges wrap
+        // around: (2, 0), (0, 2) and (2, 2).
+        assert_eq!(inital_state.get(0, 0), Cell::Alive);
+    }
+
+    #[test]
+    fn test_torus_dedupes_wrapped_neighbours_on_narrow_board() {
+        // On a width-1 torus, `x - 1` and `x + 1` both wrap onto the same
+        // column, so each physical neighbour must only be counted once.
+        let mut inital_state = board_from_rows(&[&[Cell::Alive], &[Cell::Alive], &[Cell::Alive]])
+            .with_topology(Topology::Torus);
+        inital_state.next_board_state();
+
+        // (0, 1)'s only two distinct neighbours, (0, 0) and (0, 2), are both
+        // alive, so it should survive under the default rule (S23).
+        assert_eq!(inital_state.get(0, 1), Cell::Alive);
+    }
+
+    #[test]
+    fn test_rule_parse_default_matches_conventional_life() {
+        assert_eq!(Rule::parse("B3/S23").unwrap(), Rule::default());
+    }
+
+    #[test]
+    fn test_rule_parse_highlife() {
+        let highlife = Rule::parse("B36/S23").unwrap();
+        assert!(highlife.born[3] && highlife.born[6]);
+        assert!(!highlife.born[2]);
+        assert!(highlife.survive[2] && highlife.survive[3]);
+    }
+
+    #[test]
+    fn test_rule_parse_seeds_has_no_survivors() {
+        let seeds = Rule::parse("B2/S").unwrap();
+        assert!(seeds.born[2]);
+        assert!(seeds.survive.iter().all(|&survives| !survives));
+    }
+
+    #[test]
+    fn test_rule_parse_rejects_malformed_string() {
+        assert!(Rule::parse("nonsense").is_err());
+    }
+
+    #[test]
+    fn test_rule_parse_rejects_count_above_eight() {
+        assert_eq!(
+            Rule::parse("B9/S23"),
+            Err(RuleParseError::CountOutOfRange(9))
+        );
+    }
+
+    #[test]
+    fn test_board_honours_custom_rule() {
+        // Under HighLife, a dead cell with 6 live neighbours is born, unlike
+        // under conventional Life.
+        let mut inital_state = board_from_rows(&[
+            &[Cell::Alive, Cell::Alive, Cell::Alive],
+            &[Cell::Alive, Cell::Dead, Cell::Alive],
+            &[Cell::Alive, Cell::Dead, Cell::Dead],
+        ])
+        .with_rule(Rule::parse("B36/S23").unwrap());
+        inital_state.next_board_state();
+
+        assert_eq!(inital_state.get(1, 1), Cell::Alive);
+    }
+
+    #[test]
+    fn test_from_rle_parses_glider() {
+        let glider = Board::from_rle("x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!").unwrap();
+
+        let expected = board_from_rows(&[
+            &[Cell::Dead, Cell::Alive, Cell::Dead],
+            &[Cell::Dead, Cell::Dead, Cell::Alive],
+            &[Cell::Alive, Cell::Alive, Cell::Alive],
+        ]);
+        assert_eq!(glider, expected);
+    }
+
+    #[test]
+    fn test_from_rle_ignores_comment_lines_and_defaults_rule() {
+        let board = Board::from_rle("#C a comment\nx = 2, y = 1\n2o!").unwrap();
+
+        assert_eq!(board, board_from_rows(&[&[Cell::Alive, Cell::Alive]]));
+        assert_eq!(board.rule, Rule::default());
+    }
+
+    #[test]
+    fn test_from_rle_tied_to_declared_rule() {
+        let board = Board::from_rle("x = 1, y = 1, rule = B36/S23\nb!").unwrap();
+
+        assert_eq!(board.rule, Rule::parse("B36/S23").unwrap());
+    }
+
+    #[test]
+    fn test_from_rle_rejects_missing_header() {
+        assert_eq!(Board::from_rle(""), Err(RleParseError::MissingHeader));
+    }
+
+    #[test]
+    fn test_from_rle_rejects_oversized_run_count() {
+        let rle = "x = 2, y = 2, rule = B3/S23\n99999999999999999999999999b!";
+
+        assert_eq!(Board::from_rle(rle), Err(RleParseError::RunCountOverflow));
+    }
+
+    #[test]
+    fn test_from_rle_run_count_larger_than_board_does_not_hang() {
+        // A run count far larger than the board is valid RLE (unlike the
+        // `usize`-overflowing case above) and must still terminate promptly
+        // instead of looping once per declared cell.
+        let rle = "x = 2, y = 1, rule = B3/S23\n1000000000o!";
+
+        let board = Board::from_rle(rle).unwrap();
+        assert_eq!(board, board_from_rows(&[&[Cell::Alive, Cell::Alive]]));
+    }
+
+    #[test]
+    fn test_from_rle_rejects_unexpected_token() {
+        let rle = "x = 2, y = 2, rule = B3/S23\nbx!";
+
+        assert_eq!(
+            Board::from_rle(rle),
+            Err(RleParseError::UnexpectedToken('x'))
+        );
+    }
+
+    #[test]
+    fn test_to_rle_round_trips_through_from_rle() {
+        let glider = Board::from_rle("x = 3, y = 3, rule = B3/S23\nbo$2bo$3o!").unwrap();
+        let encoded = glider.to_rle();
 
-        assert!(next_state == expected_state);
+        assert_eq!(Board::from_rle(&encoded).unwrap(), glider);
     }
 }
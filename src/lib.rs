@@ -0,0 +1,18 @@
+// Copyright (C) 2023 Sylvia Waldron
+//
+// This file is part of game_of_life.
+//
+// game_of_life is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// game_of_life is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with game_of_life.  If not, see <http://www.gnu.org/licenses/>.
+pub mod board;
+pub mod simulation;
@@ -1,12 +1,33 @@
 use std::{thread::sleep, time::Duration};
 
 use game_of_life::board::Board;
+use game_of_life::simulation::{Simulation, StepOutcome};
+
 fn main() {
-    let mut board = Board::random_state(40, 40);
+    let mut simulation = Simulation::new(Board::random_state(40, 40));
+
     loop {
         print!("\x1B[2J\x1B[1;1H"); // Clear screen, put cursor at top left
-        println!("{}", board);
-        board = board.next_board_state();
+        println!("{}", simulation.board());
+
+        match simulation.step() {
+            StepOutcome::Changed => {}
+            StepOutcome::StillLife => {
+                println!(
+                    "Stabilized into a still life after {} generations",
+                    simulation.generation()
+                );
+                break;
+            }
+            StepOutcome::Oscillator { period } => {
+                println!(
+                    "Settled into a period-{period} oscillator after {} generations",
+                    simulation.generation()
+                );
+                break;
+            }
+        }
+
         sleep(Duration::from_millis(100))
     }
 }
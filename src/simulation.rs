@@ -0,0 +1,148 @@
+// Copyright (C) 2023 Sylvia Waldron
+//
+// This file is part of game_of_life.
+//
+// game_of_life is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// game_of_life is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with game_of_life.  If not, see <http://www.gnu.org/licenses/>.
+use std::collections::VecDeque;
+
+use crate::board::{Board, Cell};
+
+/// How many past generations a [`Simulation`] keeps around to detect
+/// oscillators against.
+const HISTORY_LEN: usize = 32;
+
+/// The result of advancing a [`Simulation`] by one generation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome {
+    /// The board changed and hasn't repeated any of the recent past states.
+    Changed,
+    /// The board is identical to the previous generation.
+    StillLife,
+    /// The board matches the state from `period` generations ago.
+    Oscillator { period: usize },
+}
+
+/// Drives a [`Board`] forward one generation at a time, tracking the
+/// generation count and detecting when the board has settled into a still
+/// life or an oscillator by comparing each new state against recent history.
+pub struct Simulation {
+    board: Board,
+    generation: usize,
+    /// Snapshots of recent generations' cells, used to detect still lifes
+    /// and oscillators. Stored as bare cells rather than `Board` clones so
+    /// history doesn't carry a copy of the board's unused scratch buffer.
+    history: VecDeque<Vec<Cell>>,
+}
+
+impl Simulation {
+    pub fn new(board: Board) -> Self {
+        let mut history = VecDeque::with_capacity(HISTORY_LEN);
+        history.push_back(board.cells().to_vec());
+
+        Self {
+            board,
+            generation: 0,
+            history,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    /// Advances the simulation by one generation, returning whether the
+    /// board changed, stabilized into a still life, or fell into an
+    /// oscillation.
+    pub fn step(&mut self) -> StepOutcome {
+        self.board.next_board_state();
+        self.generation += 1;
+
+        let outcome = self.classify(self.board.cells());
+
+        self.history.push_back(self.board.cells().to_vec());
+        if self.history.len() > HISTORY_LEN {
+            self.history.pop_front();
+        }
+
+        outcome
+    }
+
+    fn classify(&self, next: &[Cell]) -> StepOutcome {
+        for (generations_ago, past) in self.history.iter().rev().enumerate() {
+            if past.as_slice() == next {
+                let period = generations_ago + 1;
+                return if period == 1 {
+                    StepOutcome::StillLife
+                } else {
+                    StepOutcome::Oscillator { period }
+                };
+            }
+        }
+
+        StepOutcome::Changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::Cell;
+
+    #[test]
+    fn test_step_increments_generation() {
+        let mut simulation = Simulation::new(Board::dead_state(3, 3));
+        simulation.step();
+        simulation.step();
+
+        assert_eq!(simulation.generation(), 2);
+    }
+
+    #[test]
+    fn test_dead_board_is_a_still_life() {
+        let mut simulation = Simulation::new(Board::dead_state(3, 3));
+
+        assert_eq!(simulation.step(), StepOutcome::StillLife);
+    }
+
+    #[test]
+    fn test_block_is_a_still_life() {
+        let mut block = Board::dead_state(4, 4);
+        block.set(1, 1, Cell::Alive);
+        block.set(2, 1, Cell::Alive);
+        block.set(1, 2, Cell::Alive);
+        block.set(2, 2, Cell::Alive);
+
+        let mut simulation = Simulation::new(block);
+        simulation.step();
+
+        assert_eq!(simulation.step(), StepOutcome::StillLife);
+    }
+
+    #[test]
+    fn test_blinker_is_a_period_two_oscillator() {
+        let mut blinker = Board::dead_state(5, 5);
+        blinker.set(1, 2, Cell::Alive);
+        blinker.set(2, 2, Cell::Alive);
+        blinker.set(3, 2, Cell::Alive);
+
+        let mut simulation = Simulation::new(blinker);
+
+        assert_eq!(simulation.step(), StepOutcome::Changed);
+        assert_eq!(simulation.step(), StepOutcome::Oscillator { period: 2 });
+    }
+}